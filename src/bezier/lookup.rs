@@ -2,6 +2,82 @@ use crate::utils::{f64_compare, TValue, TValueType};
 
 use super::*;
 
+/// Abscissae for 8-point Gauss–Legendre quadrature on the half-interval `[0, 1]`, i.e. the positive roots of the Legendre polynomial on `[-1, 1]`, each used with both signs.
+const GAUSS_LEGENDRE_8_NODES: [f64; 4] = [0.1834346424956498, 0.5255324099163290, 0.7966664774136267, 0.9602898564975363];
+/// Weights corresponding to [`GAUSS_LEGENDRE_8_NODES`].
+const GAUSS_LEGENDRE_8_WEIGHTS: [f64; 4] = [0.3626837833783620, 0.3137066458778873, 0.2223810344533745, 0.1012285362903763];
+/// Abscissae for 16-point Gauss–Legendre quadrature on the half-interval `[0, 1]`, used to estimate the error of the 8-point result.
+const GAUSS_LEGENDRE_16_NODES: [f64; 8] = [
+	0.0950125098376374,
+	0.2816035507792589,
+	0.4580167776572274,
+	0.6178762444026438,
+	0.7554044083550030,
+	0.8656312023878318,
+	0.9445750230732326,
+	0.9894009349916499,
+];
+/// Weights corresponding to [`GAUSS_LEGENDRE_16_NODES`].
+const GAUSS_LEGENDRE_16_WEIGHTS: [f64; 8] = [
+	0.1894506104550685,
+	0.1826034150449236,
+	0.1691565193950025,
+	0.1495959888165767,
+	0.1246289712555339,
+	0.0951585116824928,
+	0.0622535239386479,
+	0.0271524594117541,
+];
+/// Default maximum allowed difference between the 8- and 16-point quadrature estimates before [`Bezier::length_analytic`] subdivides the interval further.
+const DEFAULT_LENGTH_ANALYTIC_TOLERANCE: f64 = 1e-6;
+/// Maximum number of times [`Bezier::length_analytic_recursive`] will halve its interval, regardless of `tolerance`. Backstops a degenerate or overly tight tolerance (e.g. `0.`) against recursing down to floating-point-noise-sized intervals.
+/// Kept low (rather than just large) since every level doubles the node count on *both* halves when neither side's quadrature converges, so the backstop alone still bounds worst-case work to about `2^depth` nodes.
+const LENGTH_ANALYTIC_MAX_SUBDIVISION_DEPTH: usize = 10;
+/// How close together (relative to the quadratic's leading coefficient) the two roots of the inflection quadratic must be for [`Bezier::classify_cusp`] to call the curve a double inflection rather than a plain two-inflection curve.
+const CUSP_DOUBLE_INFLECTION_ROOT_SEPARATION_THRESHOLD: f64 = 1e-3;
+/// Maximum number of Newton–Raphson iterations [`Bezier::refine_projection_newton_raphson`] will run when polishing a candidate projection.
+const NEWTON_RAPHSON_PROJECTION_MAX_ITERATIONS: usize = 8;
+/// Newton–Raphson stops refining a projection once a step changes `t` by less than this amount.
+const NEWTON_RAPHSON_PROJECTION_TOLERANCE: f64 = 1e-9;
+/// Flatness tolerance used to adaptively flatten the curve when [`Bezier::length`] is called without an explicit subdivision count.
+const DEFAULT_LENGTH_FLATTEN_TOLERANCE: f64 = 1e-3;
+
+/// Options customizing the behavior of [`Bezier::project`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ProjectionOptions {
+	/// If set, the best candidate (among the curve's endpoints and the critical points from `normals_to_point`) is additionally polished with a few Newton–Raphson iterations for near-exact accuracy.
+	pub refine_with_newton_raphson: bool,
+}
+
+/// The two ways a cubic Bézier's single higher-order cusp can manifest, distinguished by the sign of the inflection quadratic's discriminant.
+/// Returned by [`Bezier::classify_cusp`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CuspType {
+	/// The inflection quadratic has a complex-conjugate pair of roots, so the curve's extended algebraic shape crosses over itself, forming a loop.
+	/// The loop need not lie within the curve's own `[0, 1]` domain: a simple arch whose extended shape loops outside that range is also reported as a `Loop`.
+	Loop,
+	/// The curve has two real inflection points so close together that they're effectively a single higher-order cusp.
+	DoubleInflection,
+}
+
+/// Solve `a·t² + b·t + c = 0` for its real roots, falling back to the linear and degenerate cases when `a` (and then `b`) vanish.
+fn quadratic_roots(a: f64, b: f64, c: f64) -> Vec<f64> {
+	if a.abs() < f64::EPSILON {
+		if b.abs() < f64::EPSILON {
+			return Vec::new();
+		}
+		return vec![-c / b];
+	}
+
+	let discriminant = b * b - 4. * a * c;
+	if discriminant < 0. {
+		Vec::new()
+	} else {
+		let sqrt_discriminant = discriminant.sqrt();
+		vec![(-b + sqrt_discriminant) / (2. * a), (-b - sqrt_discriminant) / (2. * a)]
+	}
+}
+
 /// Functionality relating to looking up properties of the `Bezier` or points along the `Bezier`.
 impl Bezier {
 	/// Convert a euclidean distance ratio along the `Bezier` curve to a parametric `t`-value.
@@ -28,23 +104,20 @@ impl Bezier {
 		// The euclidean t-value input generally correlates with the parametric t-value result.
 		// So we can assume a low t-value has a short length from the start of the curve, and a high t-value has a short length from the end of the curve.
 		// We'll use a strategy where we measure from either end of the curve depending on which side is closer than thus more likely to be proximate to the sought parametric t-value.
-		// This allows us to use fewer segments to approximate the curve, which usually won't go much beyond half the curve.
 		let result_likely_closer_to_start = euclidean_t < 0.5;
-		// If the curve is near either end, we need even fewer segments to approximate the curve with reasonable accuracy.
-		// A point that's likely near the center is the worst case where we need to use up to half the predefined number of max subdivisions.
-		let subdivisions_proportional_to_likely_length = ((euclidean_t - 0.5).abs() * DEFAULT_LENGTH_SUBDIVISIONS as f64).round().max(1.) as usize;
 
 		// Binary search for the parametric t-value that corresponds to the euclidean distance ratio by trimming the curve between the start and the tested parametric t-value during each iteration of the search.
+		// Each iteration calls the fast, accurate analytic length instead of re-sampling a lookup table, since the interval being measured shrinks with every step.
 		while low < high {
 			mid = (low + high) / 2.;
 
 			// We can search from the curve start to the sought point, or from the sought point to the curve end, depending on which side is likely closer to the result.
 			let current_length = if result_likely_closer_to_start {
 				let trimmed = self.trim(TValue::Parametric(0.), TValue::Parametric(mid));
-				trimmed.length(Some(subdivisions_proportional_to_likely_length))
+				trimmed.length_analytic(None)
 			} else {
 				let trimmed = self.trim(TValue::Parametric(mid), TValue::Parametric(1.));
-				let trimmed_length = trimmed.length(Some(subdivisions_proportional_to_likely_length));
+				let trimmed_length = trimmed.length_analytic(None);
 				total_length - trimmed_length
 			};
 			let current_euclidean_t = current_length / total_length;
@@ -124,27 +197,230 @@ impl Bezier {
 			.collect()
 	}
 
+	/// Approximate a cubic segment with a sequence of quadratic segments, each deviating from the cubic by no more than `tolerance`.
+	/// Linear and quadratic segments are already representable exactly and are returned unchanged.
+	/// Uses the pathfinder/kurbo approach: the cubic's third-order term bounds the error of a single-quadratic approximation, which gives the number of uniform splits needed to bring every piece under `tolerance`.
+	pub fn to_quadratics(&self, tolerance: f64) -> Vec<Bezier> {
+		// A non-positive tolerance would saturate segment_count to usize::MAX below.
+		assert!(tolerance > 0., "tolerance must be strictly positive");
+
+		match self.handles {
+			BezierHandles::Linear | BezierHandles::Quadratic { .. } => vec![*self],
+			BezierHandles::Cubic { handle_start, handle_end } => {
+				// The magnitude of this term governs how far a single quadratic's midpoint approximation (see `cubic_to_single_quadratic`) can drift from the cubic.
+				let third_order_term = self.end - 3. * handle_end + 3. * handle_start - self.start;
+				let max_error_per_split = 432. * tolerance * tolerance;
+				let segment_count = (third_order_term.length_squared() / max_error_per_split).powf(1. / 6.).ceil().max(1.) as usize;
+
+				(0..segment_count)
+					.map(|index| {
+						let t_start = index as f64 / segment_count as f64;
+						let t_end = (index + 1) as f64 / segment_count as f64;
+						self.trim(TValue::Parametric(t_start), TValue::Parametric(t_end)).cubic_to_single_quadratic()
+					})
+					.collect()
+			}
+		}
+	}
+
+	/// Approximate this cubic segment with a single quadratic sharing its endpoints, whose control point is the midpoint of the two points reached by extending each end's handle to 1.5 times its length: `start + 1.5·(handle_start − start)` and `end + 1.5·(handle_end − end)`.
+	/// That point equals the intersection of the two endpoint tangents only when the cubic is an exact degree-raised quadratic.
+	fn cubic_to_single_quadratic(&self) -> Bezier {
+		let BezierHandles::Cubic { handle_start, handle_end } = self.handles else {
+			panic!("cubic_to_single_quadratic should only be called on a cubic segment");
+		};
+		let handle = -0.25 * self.start + 0.75 * handle_start + 0.75 * handle_end - 0.25 * self.end;
+		Bezier::from_quadratic_dvec2(self.start, handle, self.end)
+	}
+
+	/// Return a polyline approximation of the curve whose maximum deviation from the true curve stays within `tolerance`.
+	/// Chooses the number of samples from how sharply the curve bends (more for sharp bends, fewer for nearly straight stretches) instead of relying on a fixed subdivision count.
+	/// Within each quadratic piece the samples are equally spaced in `t`; the spacing does not vary along a piece.
+	pub fn flatten(&self, tolerance: f64) -> Vec<DVec2> {
+		// A non-positive tolerance would saturate flatten_quadratic's segment count to usize::MAX.
+		assert!(tolerance > 0., "tolerance must be strictly positive");
+
+		match self.handles {
+			BezierHandles::Linear => vec![self.start, self.end],
+			BezierHandles::Quadratic { .. } => self.flatten_quadratic(tolerance),
+			BezierHandles::Cubic { .. } => {
+				// Flattening a cubic directly would need a cubic error metric; splitting into quadratics first lets us reuse the simpler quadratic flattening estimate on each piece.
+				// Each stage (cubic-to-quadratic, then quadratic-to-polyline) is independently bounded by the tolerance it's given, and the two errors add, so halve the tolerance for each stage to keep the combined deviation within the caller's `tolerance`.
+				let half_tolerance = tolerance * 0.5;
+				let quadratics = self.to_quadratics(half_tolerance);
+
+				let mut polyline = Vec::new();
+				for (index, quadratic) in quadratics.iter().enumerate() {
+					let points = quadratic.flatten_quadratic(half_tolerance);
+					if index == 0 {
+						polyline.extend(points);
+					} else {
+						// Skip the first point of each subsequent piece since it's the same as the previous piece's last point.
+						polyline.extend(&points[1..]);
+					}
+				}
+				polyline
+			}
+		}
+	}
+
+	/// Flatten a quadratic segment into a polyline within `tolerance`, using the kurbo/quadbez error-driven subdivision estimate.
+	fn flatten_quadratic(&self, tolerance: f64) -> Vec<DVec2> {
+		let BezierHandles::Quadratic { handle } = self.handles else {
+			panic!("flatten_quadratic should only be called on a quadratic segment");
+		};
+
+		// A quadratic's second derivative is constant, so this second-difference term alone governs how far any point on the curve can stray from its chord:
+		// `B(t) - chord(t) = -t(1-t)·second_difference`, which peaks at `t=0.5` with magnitude `0.25·|second_difference|`; splitting into `n` equal sub-intervals scales that peak by `1/n²`, giving a per-segment deviation of `|second_difference| / (4n²)`.
+		let second_difference = self.start - 2. * handle + self.end;
+		let segment_count = (second_difference.length() / (4. * tolerance)).sqrt().ceil().max(1.) as usize;
+
+		// Because that curvature term doesn't vary with t, equally spaced parameters already equalize the error across every segment.
+		(0..=segment_count).map(|index| self.unrestricted_parametric_evaluate(index as f64 / segment_count as f64)).collect()
+	}
+
 	/// Return an approximation of the length of the bezier curve.
-	/// - `num_subdivisions` - Number of subdivisions used to approximate the curve. The default value is 1000.
+	/// - `num_subdivisions` - Number of subdivisions used to approximate the curve. If not provided, the curve is adaptively flattened instead of using a fixed count.
 	/// <iframe frameBorder="0" width="100%" height="300px" src="https://keavon.github.io/Bezier-rs#bezier/length/solo" title="Length Demo"></iframe>
 	pub fn length(&self, num_subdivisions: Option<usize>) -> f64 {
 		match self.handles {
 			BezierHandles::Linear => (self.start - self.end).length(),
 			_ => {
-				// Code example from <https://gamedev.stackexchange.com/questions/5373/moving-ships-between-two-planets-along-a-bezier-missing-some-equations-for-acce/5427#5427>.
+				let polyline = match num_subdivisions {
+					// Code example from <https://gamedev.stackexchange.com/questions/5373/moving-ships-between-two-planets-along-a-bezier-missing-some-equations-for-acce/5427#5427>.
+					Some(num_subdivisions) => self.compute_lookup_table(Some(num_subdivisions), Some(TValueType::Parametric)),
+					// With no explicit subdivision count, adaptively flatten so flat stretches use fewer points and sharp bends use more.
+					None => self.flatten(DEFAULT_LENGTH_FLATTEN_TOLERANCE),
+				};
+
+				polyline.windows(2).map(|points| (points[1] - points[0]).length()).sum()
+			}
+		}
+	}
+
+	/// Estimate `∫ₐᵇ |B'(t)| dt`, the arc length of the curve over the interval `[a, b]`, using both 8- and 16-point Gauss–Legendre quadrature.
+	/// Returning both estimates lets the caller compare them to decide whether the interval needs to be subdivided further.
+	fn gauss_legendre_length_estimates(&self, a: f64, b: f64) -> (f64, f64) {
+		let half_interval = (b - a) * 0.5;
+		let midpoint = (a + b) * 0.5;
+		let speed_at = |t: f64| self.unrestricted_parametric_derivative(t).length();
+
+		let estimate_8: f64 = GAUSS_LEGENDRE_8_NODES
+			.iter()
+			.zip(GAUSS_LEGENDRE_8_WEIGHTS)
+			.map(|(&node, weight)| weight * (speed_at(midpoint + half_interval * node) + speed_at(midpoint - half_interval * node)))
+			.sum();
+		let estimate_16: f64 = GAUSS_LEGENDRE_16_NODES
+			.iter()
+			.zip(GAUSS_LEGENDRE_16_WEIGHTS)
+			.map(|(&node, weight)| weight * (speed_at(midpoint + half_interval * node) + speed_at(midpoint - half_interval * node)))
+			.sum();
+
+		(estimate_8 * half_interval, estimate_16 * half_interval)
+	}
+
+	/// Recursively estimate the arc length over `[a, b]`, halving the interval whenever the 8- and 16-point estimates disagree by more than `tolerance`.
+	/// Stops halving past [`LENGTH_ANALYTIC_MAX_SUBDIVISION_DEPTH`], or once `[a, b]` is too narrow for the two quadrature estimates to meaningfully differ, even if the tolerance is never met, so a caller-supplied tolerance too tight for quadrature noise to ever satisfy (e.g. `0.`) can't recurse indefinitely.
+	fn length_analytic_recursive(&self, a: f64, b: f64, tolerance: f64, depth: usize) -> f64 {
+		let (estimate_8, estimate_16) = self.gauss_legendre_length_estimates(a, b);
+
+		if (estimate_16 - estimate_8).abs() < tolerance || depth >= LENGTH_ANALYTIC_MAX_SUBDIVISION_DEPTH || b - a < f64::EPSILON {
+			estimate_16
+		} else {
+			let midpoint = (a + b) * 0.5;
+			// Halving the per-interval tolerance keeps the error bound on the full curve roughly `tolerance`, since the two halves' errors add.
+			self.length_analytic_recursive(a, midpoint, tolerance * 0.5, depth + 1) + self.length_analytic_recursive(midpoint, b, tolerance * 0.5, depth + 1)
+		}
+	}
 
-				// We will use an approximate approach where we split the curve into many subdivisions
-				// and calculate the euclidean distance between the two endpoints of the subdivision
-				let lookup_table = self.compute_lookup_table(Some(num_subdivisions.unwrap_or(DEFAULT_LENGTH_SUBDIVISIONS)), Some(TValueType::Parametric));
-				let approx_curve_length: f64 = lookup_table.windows(2).map(|points| (points[1] - points[0]).length()).sum();
+	/// Return the arc length of the curve, computed analytically by integrating the speed function `|B'(t)|` with adaptive Gauss–Legendre quadrature.
+	/// Unlike [`Bezier::length`], this doesn't approximate the curve with a polyline, so it stays both fast and accurate for highly curved segments.
+	/// - `tolerance` - The maximum allowed difference between the 8- and 16-point quadrature estimates before an interval is subdivided. Defaults to `1e-6`.
+	pub fn length_analytic(&self, tolerance: Option<f64>) -> f64 {
+		match self.handles {
+			// A line's speed is constant, so quadrature would just reproduce the exact Euclidean distance at extra cost.
+			BezierHandles::Linear => (self.start - self.end).length(),
+			_ => self.length_analytic_recursive(0., 1., tolerance.unwrap_or(DEFAULT_LENGTH_ANALYTIC_TOLERANCE), 0),
+		}
+	}
 
-				approx_curve_length
+	/// Calculate the coordinates of the tangent vector `B'(t)`, the derivative of the curve, at the point `t` along the curve.
+	/// The derivative of a degree `n` Bézier is itself a Bézier of degree `n - 1`, scaled by `n`, formed from the differences between consecutive control points.
+	pub(crate) fn unrestricted_parametric_derivative(&self, t: f64) -> DVec2 {
+		match self.handles {
+			BezierHandles::Linear => self.end - self.start,
+			BezierHandles::Quadratic { handle } => 2. * (1. - t) * (handle - self.start) + 2. * t * (self.end - handle),
+			BezierHandles::Cubic { handle_start, handle_end } => {
+				let one_minus_t = 1. - t;
+				3. * one_minus_t * one_minus_t * (handle_start - self.start) + 6. * one_minus_t * t * (handle_end - handle_start) + 3. * t * t * (self.end - handle_end)
 			}
 		}
 	}
 
+	/// Calculate the coordinates of `B''(t)`, the second derivative of the curve, at the point `t` along the curve.
+	/// The second derivative of a degree `n` Bézier is a Bézier of degree `n - 2`, scaled by `n(n - 1)`.
+	pub(crate) fn unrestricted_parametric_second_derivative(&self, t: f64) -> DVec2 {
+		match self.handles {
+			BezierHandles::Linear => DVec2::ZERO,
+			BezierHandles::Quadratic { handle } => 2. * (self.start - 2. * handle + self.end),
+			BezierHandles::Cubic { handle_start, handle_end } => 6. * ((1. - t) * (self.start - 2. * handle_start + handle_end) + t * (handle_start - 2. * handle_end + self.end)),
+		}
+	}
+
+	/// Coefficients `(a, b, c)` of the quadratic `a·t² + b·t + c`, whose roots are the parameter values where `cross(B'(t), B''(t))` vanishes, i.e. where curvature changes sign.
+	/// Expressed in the monomial (power) basis `B(t) = a₃t³ + a₂t² + a₁t + a₀`, the cross-product's `t³` term cancels identically (it is the cross product of `a₃` with itself), leaving a quadratic.
+	/// Returns `None` for linear and quadratic segments, which have no inflection points by definition.
+	fn inflection_quadratic_coefficients(&self) -> Option<(f64, f64, f64)> {
+		let BezierHandles::Cubic { handle_start, handle_end } = self.handles else {
+			return None;
+		};
+
+		let a3 = self.end - 3. * handle_end + 3. * handle_start - self.start;
+		let a2 = 3. * self.start - 6. * handle_start + 3. * handle_end;
+		let a1 = 3. * (handle_start - self.start);
+
+		let cross = |u: DVec2, v: DVec2| u.x * v.y - u.y * v.x;
+
+		Some((-6. * cross(a3, a2), 6. * cross(a1, a3), 2. * cross(a1, a2)))
+	}
+
+	/// Return the up-to-two parametric `t`-values in `[0, 1]` where the curve's inflection points occur, i.e. where curvature changes sign.
+	/// Only cubic segments can have inflection points; other curve types return an empty list.
+	pub fn inflections(&self) -> Vec<f64> {
+		let Some((a, b, c)) = self.inflection_quadratic_coefficients() else {
+			return Vec::new();
+		};
+
+		quadratic_roots(a, b, c).into_iter().filter(|t| (0. ..=1.).contains(t)).collect()
+	}
+
+	/// Classify the higher-order cusp of a cubic segment, if it has one, as a [`CuspType::Loop`] or [`CuspType::DoubleInflection`] based on the discriminant of the inflection quadratic.
+	/// Returns `None` for linear and quadratic segments, and for cubics whose inflection quadratic has two well-separated real roots (an ordinary curve with up to two distinct inflections).
+	/// A [`CuspType::DoubleInflection`] is only reported when, like [`Bezier::inflections`], both roots land within the curve's own `[0, 1]` domain; a curve whose extended algebraic shape double-inflects outside that range isn't considered to have a cusp.
+	pub fn classify_cusp(&self) -> Option<CuspType> {
+		let (a, b, c) = self.inflection_quadratic_coefficients()?;
+		if a.abs() < f64::EPSILON {
+			return None;
+		}
+
+		let discriminant = b * b - 4. * a * c;
+		if discriminant < 0. {
+			Some(CuspType::Loop)
+		} else {
+			// The two real roots, measured relative to the quadratic's leading coefficient so the comparison is invariant to the curve's overall scale.
+			let root_separation = discriminant.sqrt() / a.abs();
+			if root_separation >= CUSP_DOUBLE_INFLECTION_ROOT_SEPARATION_THRESHOLD {
+				return None;
+			}
+			// Only report a double inflection if both roots actually lie on the curve's own parametric domain.
+			let roots = quadratic_roots(a, b, c);
+			(roots.len() == 2 && roots.iter().all(|t| (0. ..=1.).contains(t))).then_some(CuspType::DoubleInflection)
+		}
+	}
+
 	/// Returns the parametric `t`-value that corresponds to the closest point on the curve to the provided point.
 	/// Uses a searching algorithm akin to binary search that can be customized using the optional [ProjectionOptions] struct.
+	/// If [`ProjectionOptions::refine_with_newton_raphson`] is set, the best candidate among the endpoints and the critical points from `normals_to_point` is additionally polished with a few Newton–Raphson iterations for near-exact accuracy.
 	/// <iframe frameBorder="0" width="100%" height="300px" src="https://keavon.github.io/Bezier-rs#bezier/project/solo" title="Project Demo"></iframe>
 	pub fn project(&self, point: DVec2, options: Option<ProjectionOptions>) -> f64 {
 		// The points at which the line from us to `point` is perpendicular
@@ -165,13 +441,81 @@ impl Bezier {
 		if self.evaluate(TValue::Parametric(1.)).distance_squared(point) < min_dist_squared {
 			closest = 1.;
 		}
+
+		if options.map(|options| options.refine_with_newton_raphson).unwrap_or(false) {
+			closest = self.refine_projection_newton_raphson(point, closest);
+		}
+
 		closest
 	}
+
+	/// One Newton–Raphson step minimizing the squared distance from the curve to `point`, starting from `t`.
+	/// Returns `t` unchanged once `f''(t)` becomes non-positive, meaning `t` is no longer at a local minimum of the squared-distance function.
+	/// Shared by [`Bezier::refine_projection_newton_raphson`] and [`crate::fitting::reparameterize`], which both minimize the same squared-distance function, just from different starting points.
+	pub(crate) fn newton_raphson_distance_step(&self, point: DVec2, t: f64) -> f64 {
+		let difference = self.unrestricted_parametric_evaluate(t) - point;
+		let derivative = self.unrestricted_parametric_derivative(t);
+		let second_derivative = self.unrestricted_parametric_second_derivative(t);
+
+		let f_prime = difference.dot(derivative);
+		let f_double_prime = derivative.length_squared() + difference.dot(second_derivative);
+
+		if f_double_prime <= 0. {
+			return t;
+		}
+
+		(t - f_prime / f_double_prime).clamp(0., 1.)
+	}
+
+	/// Polish a candidate projection `t`-value with Newton–Raphson iterations minimizing the squared distance to `point`.
+	/// Stops once a step moves `t` by less than [`NEWTON_RAPHSON_PROJECTION_TOLERANCE`] or the iteration budget is spent.
+	fn refine_projection_newton_raphson(&self, point: DVec2, initial_t: f64) -> f64 {
+		let mut t = initial_t;
+
+		for _ in 0..NEWTON_RAPHSON_PROJECTION_MAX_ITERATIONS {
+			let next_t = self.newton_raphson_distance_step(point, t);
+			let step = (next_t - t).abs();
+			t = next_t;
+
+			if step < NEWTON_RAPHSON_PROJECTION_TOLERANCE {
+				break;
+			}
+		}
+
+		t
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+
+	/// Densely sample `curve` and return the largest distance from any sample to its nearest point in `approximation`, as a cheap proxy for the Hausdorff distance between them.
+	fn max_sampled_deviation(curve: &Bezier, approximation: &[DVec2]) -> f64 {
+		(0..=256)
+			.map(|step| curve.unrestricted_parametric_evaluate(step as f64 / 256.))
+			.map(|point| approximation.iter().map(|&sample| sample.distance_squared(point)).fold(f64::INFINITY, f64::min).sqrt())
+			.fold(0., f64::max)
+	}
+
+	/// The shortest distance from `point` to the line segment `a..b`, clamping the projection onto the segment rather than the infinite line.
+	fn distance_to_segment(point: DVec2, a: DVec2, b: DVec2) -> f64 {
+		let segment = b - a;
+		let length_squared = segment.length_squared();
+		if length_squared < f64::EPSILON {
+			return point.distance(a);
+		}
+		let t = ((point - a).dot(segment) / length_squared).clamp(0., 1.);
+		point.distance(a + segment * t)
+	}
+
+	/// Densely sample `curve` and return the largest distance from any sample to its nearest point on the polyline `approximation` (measuring against segments, not just vertices), as a cheap proxy for the Hausdorff distance between them.
+	fn max_polyline_deviation(curve: &Bezier, approximation: &[DVec2]) -> f64 {
+		(0..=256)
+			.map(|step| curve.unrestricted_parametric_evaluate(step as f64 / 256.))
+			.map(|point| approximation.windows(2).map(|segment| distance_to_segment(point, segment[0], segment[1])).fold(f64::INFINITY, f64::min))
+			.fold(0., f64::max)
+	}
 	#[test]
 	fn test_evaluate() {
 		let p1 = DVec2::new(3., 5.);
@@ -223,6 +567,137 @@ mod tests {
 		assert!(utils::f64_compare(bezier_cubic.length(None), 199., 1e-2));
 	}
 
+	#[test]
+	fn test_length_analytic() {
+		let p1 = DVec2::new(30., 50.);
+		let p2 = DVec2::new(140., 30.);
+		let p3 = DVec2::new(160., 170.);
+		let p4 = DVec2::new(77., 129.);
+
+		let bezier_linear = Bezier::from_linear_dvec2(p1, p2);
+		assert!(utils::f64_compare(bezier_linear.length_analytic(None), p1.distance(p2), MAX_ABSOLUTE_DIFFERENCE));
+
+		let bezier_quadratic = Bezier::from_quadratic_dvec2(p1, p2, p3);
+		assert!(utils::f64_compare(bezier_quadratic.length_analytic(None), bezier_quadratic.length(None), 1e-2));
+
+		let bezier_cubic = Bezier::from_cubic_dvec2(p1, p2, p3, p4);
+		assert!(utils::f64_compare(bezier_cubic.length_analytic(None), bezier_cubic.length(None), 1e-2));
+	}
+
+	#[test]
+	fn test_length_analytic_zero_tolerance_terminates() {
+		// A tolerance of `0.` can never be satisfied by the 8- vs 16-point quadrature comparison, so this would recurse forever without the max subdivision depth backstop.
+		let bezier_cubic = Bezier::from_cubic_coordinates(30., 50., 140., 30., 160., 170., 77., 129.);
+		let length = bezier_cubic.length_analytic(Some(0.));
+		assert!(utils::f64_compare(length, bezier_cubic.length(None), 1e-2));
+	}
+
+	#[test]
+	fn test_flatten() {
+		let bezier_linear = Bezier::from_linear_coordinates(10., 10., 50., 50.);
+		assert_eq!(bezier_linear.flatten(0.1), vec![bezier_linear.start(), bezier_linear.end()]);
+
+		let tolerance = 0.1;
+
+		let bezier_quadratic = Bezier::from_quadratic_coordinates(10., 10., 30., 90., 50., 10.);
+		let quadratic_polyline = bezier_quadratic.flatten(tolerance);
+		assert!(quadratic_polyline.len() >= 2);
+		assert_eq!(quadratic_polyline[0], bezier_quadratic.start());
+		assert_eq!(*quadratic_polyline.last().unwrap(), bezier_quadratic.end());
+		// Measured against the polyline's segments, not just its vertices, since the deviation bound is on the Hausdorff distance to the whole polyline.
+		assert!(max_polyline_deviation(&bezier_quadratic, &quadratic_polyline) <= tolerance);
+
+		let bezier_cubic = Bezier::from_cubic_coordinates(30., 50., 140., 30., 160., 170., 77., 129.);
+		let cubic_polyline = bezier_cubic.flatten(tolerance);
+		assert!(cubic_polyline.len() >= 2);
+		assert_eq!(cubic_polyline[0], bezier_cubic.start());
+		assert_eq!(*cubic_polyline.last().unwrap(), bezier_cubic.end());
+		assert!(max_polyline_deviation(&bezier_cubic, &cubic_polyline) <= tolerance);
+	}
+
+	#[test]
+	#[should_panic(expected = "tolerance must be strictly positive")]
+	fn test_flatten_rejects_non_positive_tolerance() {
+		let bezier_quadratic = Bezier::from_quadratic_coordinates(10., 10., 30., 90., 50., 10.);
+		bezier_quadratic.flatten(0.);
+	}
+
+	#[test]
+	fn test_to_quadratics() {
+		let bezier_linear = Bezier::from_linear_coordinates(10., 10., 50., 50.);
+		let linear_result = bezier_linear.to_quadratics(0.1);
+		assert_eq!(linear_result.len(), 1);
+		assert_eq!(linear_result[0].start(), bezier_linear.start());
+		assert_eq!(linear_result[0].end(), bezier_linear.end());
+
+		let bezier_quadratic = Bezier::from_quadratic_coordinates(10., 10., 30., 30., 50., 10.);
+		let quadratic_result = bezier_quadratic.to_quadratics(0.1);
+		assert_eq!(quadratic_result.len(), 1);
+		assert_eq!(quadratic_result[0].handles, bezier_quadratic.handles);
+
+		let tolerance = 0.1;
+		let bezier_cubic = Bezier::from_cubic_coordinates(30., 50., 140., 30., 160., 170., 77., 129.);
+		let quadratics = bezier_cubic.to_quadratics(tolerance);
+		assert!(!quadratics.is_empty());
+		for quadratic in &quadratics {
+			assert!(matches!(quadratic.handles, BezierHandles::Quadratic { .. }));
+		}
+
+		// The quadratics partition the full curve, so densely sampling all of them should stay within `tolerance` of every point on the original cubic.
+		let samples: Vec<DVec2> = quadratics.iter().flat_map(|quadratic| (0..=64).map(|step| quadratic.unrestricted_parametric_evaluate(step as f64 / 64.))).collect();
+		assert!(max_sampled_deviation(&bezier_cubic, &samples) <= tolerance);
+	}
+
+	#[test]
+	#[should_panic(expected = "tolerance must be strictly positive")]
+	fn test_to_quadratics_rejects_non_positive_tolerance() {
+		let bezier_cubic = Bezier::from_cubic_coordinates(30., 50., 140., 30., 160., 170., 77., 129.);
+		bezier_cubic.to_quadratics(0.);
+	}
+
+	#[test]
+	fn test_inflections() {
+		// A cubic with no inflections: a simple convex arc.
+		let simple_cubic = Bezier::from_cubic_coordinates(10., 10., 30., 30., 50., 30., 70., 10.);
+		assert!(simple_cubic.inflections().is_empty());
+
+		// A cubic with a classic S-shape has exactly one inflection point.
+		let s_cubic = Bezier::from_cubic_coordinates(0., 0., 100., 0., 0., 100., 100., 100.);
+		let inflections = s_cubic.inflections();
+		assert_eq!(inflections.len(), 1);
+		assert!((0. ..=1.).contains(&inflections[0]));
+
+		// Linear and quadratic segments have no inflections by definition.
+		let bezier_linear = Bezier::from_linear_coordinates(0., 0., 10., 10.);
+		assert!(bezier_linear.inflections().is_empty());
+		let bezier_quadratic = Bezier::from_quadratic_coordinates(0., 0., 10., 10., 20., 0.);
+		assert!(bezier_quadratic.inflections().is_empty());
+	}
+
+	#[test]
+	fn test_classify_cusp() {
+		// A self-intersecting cubic forms a loop.
+		let looped_cubic = Bezier::from_cubic_coordinates(0., 0., 100., 100., 100., -100., 0., 0.);
+		assert_eq!(looped_cubic.classify_cusp(), Some(CuspType::Loop));
+
+		// A simple convex arc has no cusp.
+		let simple_cubic = Bezier::from_cubic_coordinates(10., 10., 30., 30., 50., 30., 70., 10.);
+		assert_eq!(simple_cubic.classify_cusp(), None);
+
+		// Linear and quadratic segments have no cusp.
+		let bezier_linear = Bezier::from_linear_coordinates(0., 0., 10., 10.);
+		assert_eq!(bezier_linear.classify_cusp(), None);
+
+		// This cubic's inflection quadratic has two nearly-coincident roots (t ≈ 1.4999 and t ≈ 1.5006), but both lie outside [0, 1], so it isn't a real double inflection.
+		let out_of_range_double_inflection = Bezier::from_cubic_coordinates(0., 0., 1., 0., 2., 1., 2.55571112, 1.6669);
+		assert_eq!(out_of_range_double_inflection.classify_cusp(), None);
+		assert!(out_of_range_double_inflection.inflections().is_empty());
+
+		// This cubic's inflection quadratic has two nearly-coincident roots (t ≈ 0.3987 and t ≈ 0.3985), both within [0, 1], so it's a genuine double inflection.
+		let double_inflection = Bezier::from_cubic_coordinates(0., 0., 1.655004, 1.647022, 0.456719, 1.195540, 0.304763, -1.191980);
+		assert_eq!(double_inflection.classify_cusp(), Some(CuspType::DoubleInflection));
+	}
+
 	#[test]
 	fn test_project() {
 		let bezier1 = Bezier::from_cubic_coordinates(4., 4., 23., 45., 10., 30., 56., 90.);
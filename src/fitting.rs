@@ -0,0 +1,215 @@
+use crate::utils::TValue;
+use crate::{Bezier, Subpath};
+use glam::DVec2;
+
+/// Number of Newton–Raphson reparameterization passes attempted on a segment before giving up and splitting it at its worst point.
+const MAX_REPARAMETERIZATION_ITERATIONS: usize = 4;
+/// Below this squared tangent length, two points are considered coincident and a fallback direction is used instead.
+const MINIMUM_TANGENT_LENGTH_SQUARED: f64 = 1e-12;
+
+/// Fit an ordered slice of digitized points to a [`Subpath`] of G1-continuous cubic Bézier segments, using Schneider's curve-fitting algorithm from "Graphics Gems".
+/// Each segment's maximum deviation from its corresponding points is kept within `max_error`.
+/// Expects at least two points; fewer than that cannot form a curve.
+pub fn fit_cubic_bezier_path(points: &[DVec2], max_error: f64) -> Subpath {
+	assert!(points.len() >= 2);
+	// A non-positive max_error can never be satisfied, sending every segment down the splitting path with no point left to split at.
+	assert!(max_error > 0., "max_error must be strictly positive");
+
+	let left_tangent = estimate_endpoint_tangent(points[0], points[1]);
+	let right_tangent = estimate_endpoint_tangent(points[points.len() - 1], points[points.len() - 2]);
+
+	let mut beziers = Vec::new();
+	fit_cubic(points, left_tangent, right_tangent, max_error, &mut beziers);
+
+	Subpath::from_beziers(&beziers, false)
+}
+
+/// Unit tangent pointing from `point` towards `neighbor`, falling back to the X axis if the two points are coincident.
+fn estimate_endpoint_tangent(point: DVec2, neighbor: DVec2) -> DVec2 {
+	let tangent = neighbor - point;
+	if tangent.length_squared() < MINIMUM_TANGENT_LENGTH_SQUARED {
+		DVec2::new(1., 0.)
+	} else {
+		tangent.normalize()
+	}
+}
+
+/// Fit `points` with a single cubic, reparameterizing and finally splitting as needed to stay within `max_error`, pushing the resulting segments onto `beziers`.
+fn fit_cubic(points: &[DVec2], left_tangent: DVec2, right_tangent: DVec2, max_error: f64, beziers: &mut Vec<Bezier>) {
+	if points.len() == 2 {
+		// Two points is a degenerate base case: any cubic through both points fits them exactly, so place each handle a third of the chord along its tangent and skip the singular least-squares system.
+		let distance = points[0].distance(points[1]) / 3.;
+		beziers.push(Bezier::from_cubic_dvec2(points[0], points[0] + left_tangent * distance, points[1] + right_tangent * distance, points[1]));
+		return;
+	}
+
+	let mut parameters = chord_length_parameterize(points);
+	let mut bezier = generate_bezier(points, &parameters, left_tangent, right_tangent);
+	let (mut max_error_found, mut worst_index) = compute_max_error(points, &bezier, &parameters);
+	// `compute_max_error` reports squared distances, so compare them against the squared tolerance.
+	let max_error_squared = max_error * max_error;
+
+	if max_error_found < max_error_squared {
+		beziers.push(bezier);
+		return;
+	}
+
+	for _ in 0..MAX_REPARAMETERIZATION_ITERATIONS {
+		if max_error_found < max_error_squared {
+			break;
+		}
+		parameters = reparameterize(points, &bezier, &parameters);
+		bezier = generate_bezier(points, &parameters, left_tangent, right_tangent);
+		(max_error_found, worst_index) = compute_max_error(points, &bezier, &parameters);
+	}
+
+	if max_error_found < max_error_squared {
+		beziers.push(bezier);
+		return;
+	}
+
+	// Reparameterization couldn't bring the error down, so split at the point with the worst deviation and recurse on both halves.
+	// `estimate_split_tangent` points backward from the split towards the left half, so the left half uses it directly and the right half negates it to point forward into its own body.
+	let split_tangent = estimate_split_tangent(points, worst_index);
+	fit_cubic(&points[..=worst_index], left_tangent, split_tangent, max_error, beziers);
+	fit_cubic(&points[worst_index..], -split_tangent, right_tangent, max_error, beziers);
+}
+
+/// Unit tangent shared by both halves produced when splitting at `index`, estimated from the chord on either side of the split point.
+fn estimate_split_tangent(points: &[DVec2], index: usize) -> DVec2 {
+	let before = points[index - 1] - points[index];
+	let after = points[index] - points[index + 1];
+	let tangent = before + after;
+	if tangent.length_squared() < MINIMUM_TANGENT_LENGTH_SQUARED {
+		// The path doubles back at the split point, so the two chords cancel; use the direction perpendicular to the incoming chord instead.
+		let incoming = estimate_endpoint_tangent(points[index], points[index - 1]);
+		DVec2::new(-incoming.y, incoming.x)
+	} else {
+		tangent.normalize()
+	}
+}
+
+/// Assign each point a parameter in `[0, 1]` proportional to its cumulative chord length along the polyline.
+fn chord_length_parameterize(points: &[DVec2]) -> Vec<f64> {
+	let mut cumulative_length = Vec::with_capacity(points.len());
+	cumulative_length.push(0.);
+	for window in points.windows(2) {
+		let length = cumulative_length.last().unwrap() + window[0].distance(window[1]);
+		cumulative_length.push(length);
+	}
+
+	let total_length = *cumulative_length.last().unwrap();
+	if total_length <= 0. {
+		return vec![0.; points.len()];
+	}
+	cumulative_length.iter().map(|length| length / total_length).collect()
+}
+
+/// The four cubic Bernstein basis polynomials `B₀..B₃`, evaluated at `t`.
+fn cubic_bernstein_basis(t: f64) -> [f64; 4] {
+	let one_minus_t = 1. - t;
+	[one_minus_t * one_minus_t * one_minus_t, 3. * one_minus_t * one_minus_t * t, 3. * one_minus_t * t * t, t * t * t]
+}
+
+/// Solve the constrained least-squares system for the tangent-handle distances `α₁, α₂`, with the endpoints fixed at `points`' first and last entries.
+fn generate_bezier(points: &[DVec2], parameters: &[f64], left_tangent: DVec2, right_tangent: DVec2) -> Bezier {
+	let first = points[0];
+	let last = *points.last().unwrap();
+
+	// Normal-equation matrix `c` and right-hand side `x` for the 2x2 system solving for (alpha_left, alpha_right).
+	let mut c = [[0.; 2]; 2];
+	let mut x = [0.; 2];
+
+	for (&point, &u) in points.iter().zip(parameters) {
+		let basis = cubic_bernstein_basis(u);
+		let a1 = left_tangent * basis[1];
+		let a2 = right_tangent * basis[2];
+
+		c[0][0] += a1.dot(a1);
+		c[0][1] += a1.dot(a2);
+		c[1][1] += a2.dot(a2);
+
+		let endpoint_contribution = first * (basis[0] + basis[1]) + last * (basis[2] + basis[3]);
+		let shortfall = point - endpoint_contribution;
+		x[0] += a1.dot(shortfall);
+		x[1] += a2.dot(shortfall);
+	}
+	c[1][0] = c[0][1];
+
+	let determinant = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+	let chord_third = first.distance(last) / 3.;
+
+	let (alpha_left, alpha_right) = if determinant.abs() > f64::EPSILON {
+		let determinant_left = x[0] * c[1][1] - x[1] * c[0][1];
+		let determinant_right = c[0][0] * x[1] - c[1][0] * x[0];
+		(determinant_left / determinant, determinant_right / determinant)
+	} else {
+		(0., 0.)
+	};
+
+	// A non-positive or implausibly tiny solution means the system was ill-conditioned (e.g. nearly collinear points), so fall back to a third of the chord length.
+	if alpha_left < chord_third * 1e-6 || alpha_right < chord_third * 1e-6 {
+		Bezier::from_cubic_dvec2(first, first + left_tangent * chord_third, last + right_tangent * chord_third, last)
+	} else {
+		Bezier::from_cubic_dvec2(first, first + left_tangent * alpha_left, last + right_tangent * alpha_right, last)
+	}
+}
+
+/// Find the largest squared deviation between `points` and `bezier`, and the index of the point where it occurs.
+fn compute_max_error(points: &[DVec2], bezier: &Bezier, parameters: &[f64]) -> (f64, usize) {
+	let mut max_error = 0.;
+	let mut worst_index = 0;
+
+	for (index, (&point, &u)) in points.iter().zip(parameters).enumerate() {
+		let distance_squared = bezier.evaluate(TValue::Parametric(u)).distance_squared(point);
+		if distance_squared > max_error {
+			max_error = distance_squared;
+			worst_index = index;
+		}
+	}
+
+	(max_error, worst_index)
+}
+
+/// Refine each point's parameter with a Newton–Raphson root-finding step on the squared-distance function, pulling it closer to its true closest `t`-value on `bezier`.
+fn reparameterize(points: &[DVec2], bezier: &Bezier, parameters: &[f64]) -> Vec<f64> {
+	points.iter().zip(parameters).map(|(&point, &u)| bezier.newton_raphson_distance_step(point, u)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fit_cubic_bezier_path_split_stays_within_max_error() {
+		// A sharp zigzag that no single cubic can reparameterize into within a tight error bound, forcing `fit_cubic` to split at least once.
+		let points = [
+			DVec2::new(0., 0.),
+			DVec2::new(10., 40.),
+			DVec2::new(20., 0.),
+			DVec2::new(30., 40.),
+			DVec2::new(40., 0.),
+			DVec2::new(50., 40.),
+		];
+		let max_error = 0.25;
+
+		let subpath = fit_cubic_bezier_path(&points, max_error);
+		let segments: Vec<Bezier> = subpath.iter().collect();
+		assert!(segments.len() > 1, "a tight error bound on a sharp zigzag should force at least one split");
+
+		// Every digitized point should land within `max_error` of the fitted path, regardless of which segment its handle ended up pointing into.
+		let samples: Vec<DVec2> = segments.iter().flat_map(|bezier| (0..=64).map(|step| bezier.evaluate(TValue::Parametric(step as f64 / 64.)))).collect();
+		for &point in &points {
+			let closest_distance_squared = samples.iter().map(|&sample| sample.distance_squared(point)).fold(f64::INFINITY, f64::min);
+			assert!(closest_distance_squared.sqrt() <= max_error, "point {point:?} strayed more than max_error from the fitted path");
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "max_error must be strictly positive")]
+	fn test_fit_cubic_bezier_path_rejects_non_positive_max_error() {
+		// Collinear points fit a straight cubic exactly, so `compute_max_error`'s `0.` sentinel is never exceeded; a non-positive `max_error` can never be satisfied.
+		let points = [DVec2::new(0., 0.), DVec2::new(1., 0.), DVec2::new(2., 0.), DVec2::new(3., 0.)];
+		fit_cubic_bezier_path(&points, 0.);
+	}
+}
@@ -4,11 +4,13 @@
 mod bezier;
 mod compare;
 mod consts;
+mod fitting;
 mod poisson_disk;
 mod polynomial;
 mod subpath;
 mod utils;
 
 pub use bezier::*;
+pub use fitting::fit_cubic_bezier_path;
 pub use subpath::*;
 pub use utils::{Cap, Join, SubpathTValue, TValue, TValueType};